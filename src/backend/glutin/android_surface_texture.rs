@@ -6,24 +6,112 @@ use crate::backend::{self, Backend};
 use crate::context;
 use crate::debug;
 use crate::{Frame, IncompatibleOpenGl, SwapBuffersError};
-use std::cell::{Ref, RefCell};
+use std::cell::{Cell, Ref, RefCell};
+use std::ffi::CString;
 use std::ops::Deref;
 use std::os::raw::c_void;
 use std::rc::Rc;
 use takeable_option::Takeable;
 
-/// A SurfaceBacked glutin context.
+/// Minimal EGL declarations needed to bind a Wayland display and import client buffers as
+/// `EGLImage`s. Kept separate from the GL bindings glium already generates elsewhere, since
+/// these are resolved dynamically through `get_proc_address` exactly like the GL functions are.
+mod egl {
+    use std::os::raw::{c_int, c_uint, c_void};
+
+    pub type Boolean = c_uint;
+    pub type Display = *mut c_void;
+    pub type ClientBuffer = *mut c_void;
+    pub type Context = *mut c_void;
+    pub type ImageKhr = *mut c_void;
+    pub type Enum = c_uint;
+    pub type Int = c_int;
+
+    pub const FALSE: Boolean = 0;
+    pub const NO_CONTEXT: Context = std::ptr::null_mut();
+    /// `EGL_WAYLAND_BUFFER_WL`, the `eglCreateImageKHR` target for a `wl_buffer`.
+    pub const WAYLAND_BUFFER_WL: Enum = 0x31D8;
+
+    pub type BindWaylandDisplayWl =
+        unsafe extern "C" fn(dpy: Display, wl_display: *mut c_void) -> Boolean;
+    pub type CreateImageKhr = unsafe extern "C" fn(
+        dpy: Display,
+        ctx: Context,
+        target: Enum,
+        buffer: ClientBuffer,
+        attrib_list: *const Int,
+    ) -> ImageKhr;
+    pub type DestroyImageKhr = unsafe extern "C" fn(dpy: Display, image: ImageKhr) -> Boolean;
+}
+
+/// Resolve `symbol` through `get_proc_address` and reinterpret it as the function pointer type
+/// `F`, or `None` if the entry point isn't available.
+unsafe fn load_proc<F: Copy>(
+    get_proc_address: &dyn Fn(&str) -> *const c_void,
+    symbol: &str,
+) -> Option<F> {
+    let ptr = get_proc_address(symbol);
+    if ptr.is_null() {
+        None
+    } else {
+        Some(std::mem::transmute_copy(&ptr))
+    }
+}
+
+/// A glium context backed by the legacy `glutin::Context<T>` API and an Android
+/// `SurfaceTexture`.
+///
+/// See `RwhSurfaceBacked` for the equivalent built on the newer `raw-window-handle`-based
+/// `Display`/`Config`/`Context`/`Surface` split. The two are separate types (rather than cases
+/// of one enum) so that the Android-`SurfaceTexture`-specific methods below — `gl_context`,
+/// `resize`, `update_tex_image`, `external_texture`, `transform_matrix` — simply don't exist on
+/// an `RwhSurfaceBacked`, instead of panicking if called on one.
 pub struct SurfaceBacked {
     context: Rc<context::Context>,
-    glutin: GlutinBackend,
-    //android_surface: SurfaceTexture,
+    glutin: Rc<RefCell<Takeable<glutin::Context<Pc>>>>,
+    surface_texture: Rc<RefCell<SurfaceTexture>>,
+    texture_id: u32,
+    dimensions: Rc<Cell<(u32, u32)>>,
+    get_proc_address: Rc<dyn Fn(&str) -> *const c_void>,
+    /// Kept alive for as long as `self` when this context was created sharing GL objects with
+    /// another `SurfaceBacked`'s context (see `new_shared`), since GL considers the two
+    /// contexts' namespaces shared rather than copied.
+    _shared_with: Option<Rc<context::Context>>,
 }
 
 /// An implementation of the `Backend` trait for a glutin SurfaceBacked context.
 pub struct GlutinBackend {
     glutin_context: Rc<RefCell<Takeable<glutin::Context<Pc>>>>,
-    surface_texture: SurfaceTexture,
+    surface_texture: Rc<RefCell<SurfaceTexture>>,
     texture_id: u32,
+    dimensions: Rc<Cell<(u32, u32)>>,
+}
+
+/// A glium context built on top of the `raw-window-handle`-based glutin
+/// `Display`/`Config`/`Context`/`Surface` split, instead of the legacy `glutin::Context` API.
+///
+/// `context` is made current on `surface` immediately; presentation is then driven by
+/// `surface`'s own `swap_buffers`, and the reported framebuffer size always reflects the
+/// surface's current size. This lets glium targets that already depend on modern glutin (and
+/// not on winit) avoid the legacy context API entirely. See `SurfaceBacked` for the Android
+/// `SurfaceTexture`-backed equivalent.
+pub struct RwhSurfaceBacked<T: glutin::surface::SurfaceTypeTrait> {
+    context: Rc<context::Context>,
+    gl_context: Rc<glutin::context::PossiblyCurrentContext>,
+    surface: Rc<glutin::surface::Surface<T>>,
+    get_proc_address: Rc<dyn Fn(&str) -> *const c_void>,
+    /// Kept alive for as long as `self` when this context was created sharing GL objects with
+    /// another `RwhSurfaceBacked`'s context (see `from_surface_shared`), since GL considers the
+    /// two contexts' namespaces shared rather than copied.
+    _shared_with: Option<Rc<context::Context>>,
+}
+
+/// An implementation of the `Backend` trait for the `raw-window-handle`-based glutin
+/// `Display`/`Context`/`Surface` split.
+struct RwhBackend<T: glutin::surface::SurfaceTypeTrait> {
+    display: glutin::display::Display,
+    context: Rc<glutin::context::PossiblyCurrentContext>,
+    surface: Rc<glutin::surface::Surface<T>>,
 }
 
 impl Deref for SurfaceBacked {
@@ -36,7 +124,7 @@ impl Deref for SurfaceBacked {
 impl Deref for GlutinBackend {
     type Target = Rc<RefCell<Takeable<glutin::Context<Pc>>>>;
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.glutin_context
     }
 }
 
@@ -48,26 +136,126 @@ unsafe impl Backend for GlutinBackend {
 
     #[inline]
     unsafe fn get_proc_address(&self, symbol: &str) -> *const c_void {
-        self.0.borrow().get_proc_address(symbol) as *const _
+        self.glutin_context.borrow().get_proc_address(symbol) as *const _
     }
 
     #[inline]
     fn get_framebuffer_dimensions(&self) -> (u32, u32) {
-        (800, 600) // FIXME: these are random
+        self.dimensions.get()
     }
 
     #[inline]
     fn is_current(&self) -> bool {
-        self.0.borrow().is_current()
+        self.glutin_context.borrow().is_current()
     }
 
     #[inline]
     unsafe fn make_current(&self) {
-        let mut gl_window_takeable = self.0.borrow_mut();
+        let mut gl_window_takeable = self.glutin_context.borrow_mut();
         let gl_window = Takeable::take(&mut gl_window_takeable);
         let gl_window_new = gl_window.make_current().unwrap();
         Takeable::insert(&mut gl_window_takeable, gl_window_new);
-        self.surface_texture.attach_to_gl_context(self.texture_id);
+        self.surface_texture
+            .borrow()
+            .attach_to_gl_context(self.texture_id);
+    }
+}
+
+unsafe impl<T: glutin::surface::SurfaceTypeTrait> Backend for RwhBackend<T> {
+    #[inline]
+    fn swap_buffers(&self) -> Result<(), SwapBuffersError> {
+        // A failed swap at this layer means the native surface or context is gone, not that
+        // glium's own Frame-tracking double-swapped — that bookkeeping error can't happen here,
+        // since `Surface::swap_buffers` knows nothing about glium's `Frame`.
+        self.surface
+            .swap_buffers(&self.context)
+            .map_err(|_| SwapBuffersError::ContextLost)
+    }
+
+    #[inline]
+    unsafe fn get_proc_address(&self, symbol: &str) -> *const c_void {
+        let symbol = CString::new(symbol).unwrap();
+        self.display.get_proc_address(symbol.as_c_str())
+    }
+
+    #[inline]
+    fn get_framebuffer_dimensions(&self) -> (u32, u32) {
+        (
+            self.surface.width().unwrap_or(0),
+            self.surface.height().unwrap_or(0),
+        )
+    }
+
+    #[inline]
+    fn is_current(&self) -> bool {
+        self.context.is_current()
+    }
+
+    #[inline]
+    unsafe fn make_current(&self) {
+        self.context
+            .make_current(&self.surface)
+            .expect("failed to make the glutin context current");
+    }
+}
+
+impl<T: glutin::surface::SurfaceTypeTrait> Deref for RwhSurfaceBacked<T> {
+    type Target = context::Context;
+    fn deref(&self) -> &context::Context {
+        &self.context
+    }
+}
+
+impl<T: glutin::surface::SurfaceTypeTrait> backend::Facade for RwhSurfaceBacked<T> {
+    #[inline]
+    fn get_context(&self) -> &Rc<context::Context> {
+        &self.context
+    }
+}
+
+/// The GL texture target used for Android `SurfaceTexture`-backed external images,
+/// `GL_TEXTURE_EXTERNAL_OES`.
+pub const TEXTURE_EXTERNAL_OES: u32 = 0x8D65;
+
+/// `GL_FRAMEBUFFER`, the bind target used by `FramebufferBacked`.
+const GL_FRAMEBUFFER: u32 = 0x8D40;
+
+/// A GL texture name bound to `GL_TEXTURE_EXTERNAL_OES`: either the texture that
+/// `SurfaceBacked::update_tex_image` latches an Android `SurfaceTexture`'s most recent buffer
+/// into, or one created by `texture_from_wl_buffer` from a Wayland client buffer.
+///
+/// Sample it in GLSL through a `samplerExternalOES` uniform, which requires the
+/// `GL_OES_EGL_image_external` extension. For a `SurfaceTexture`-backed texture, texture
+/// coordinates must first be corrected with `SurfaceBacked::transform_matrix`, since the
+/// producer side may deliver the buffer rotated or cropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExternalTexture {
+    texture_id: u32,
+}
+
+impl ExternalTexture {
+    /// Wrap a GL texture name that is already bound to `GL_TEXTURE_EXTERNAL_OES`.
+    #[inline]
+    pub fn from_texture_id(texture_id: u32) -> Self {
+        ExternalTexture { texture_id }
+    }
+
+    /// The underlying GL texture name.
+    #[inline]
+    pub fn texture_id(&self) -> u32 {
+        self.texture_id
+    }
+
+    /// The GL texture target this texture must be bound to, `GL_TEXTURE_EXTERNAL_OES`.
+    ///
+    /// There's no `AsUniformValue` impl here: binding a `GL_TEXTURE_EXTERNAL_OES` sampler needs
+    /// its own `UniformValue` variant and matching bind logic in the program/draw-call code that
+    /// binds regular `Texture2d` uniforms, neither of which live in this file. Until that lands,
+    /// callers bind this texture themselves with `texture_id()`/`target()` (e.g. via
+    /// `glBindTexture`/`glUniform1i` ahead of a glium `draw()` call).
+    #[inline]
+    pub fn target(&self) -> u32 {
+        TEXTURE_EXTERNAL_OES
     }
 }
 
@@ -87,8 +275,15 @@ impl SurfaceBacked {
         context: glutin::Context<T>,
         surface_texture: SurfaceTexture,
         texture_id: u32,
+        dimensions: (u32, u32),
     ) -> Result<Self, IncompatibleOpenGl> {
-        Self::with_debug(context, Default::default(), surface_texture, texture_id)
+        Self::with_debug(
+            context,
+            Default::default(),
+            surface_texture,
+            texture_id,
+            dimensions,
+        )
     }
 
     /// Create a new glium `SurfaceBacked` context.
@@ -99,8 +294,15 @@ impl SurfaceBacked {
         context: glutin::Context<T>,
         surface_texture: SurfaceTexture,
         texture_id: u32,
+        dimensions: (u32, u32),
     ) -> Result<Self, IncompatibleOpenGl> {
-        Self::unchecked_with_debug(context, Default::default(), surface_texture, texture_id)
+        Self::unchecked_with_debug(
+            context,
+            Default::default(),
+            surface_texture,
+            texture_id,
+            dimensions,
+        )
     }
 
     /// The same as the `new` constructor, but allows for specifying debug callback behaviour.
@@ -109,8 +311,16 @@ impl SurfaceBacked {
         debug: debug::DebugCallbackBehavior,
         surface_texture: SurfaceTexture,
         texture_id: u32,
+        dimensions: (u32, u32),
     ) -> Result<Self, IncompatibleOpenGl> {
-        Self::new_inner(context, debug, true, surface_texture, texture_id)
+        Self::new_inner(
+            context,
+            debug,
+            true,
+            surface_texture,
+            texture_id,
+            dimensions,
+        )
     }
 
     /// The same as the `unchecked` constructor, but allows for specifying debug callback behaviour.
@@ -119,8 +329,16 @@ impl SurfaceBacked {
         debug: debug::DebugCallbackBehavior,
         surface_texture: SurfaceTexture,
         texture_id: u32,
+        dimensions: (u32, u32),
     ) -> Result<Self, IncompatibleOpenGl> {
-        Self::new_inner(context, debug, false, surface_texture, texture_id)
+        Self::new_inner(
+            context,
+            debug,
+            false,
+            surface_texture,
+            texture_id,
+            dimensions,
+        )
     }
 
     fn new_inner<T: ContextCurrentState>(
@@ -129,26 +347,125 @@ impl SurfaceBacked {
         checked: bool,
         surface_texture: SurfaceTexture,
         texture_id: u32,
+        dimensions: (u32, u32),
     ) -> Result<Self, IncompatibleOpenGl> {
         let context = unsafe { context.treat_as_current() };
         let glutin_context = Rc::new(RefCell::new(Takeable::new(context)));
+        let surface_texture = Rc::new(RefCell::new(surface_texture));
+        let dimensions = Rc::new(Cell::new(dimensions));
         let glutin_backend = GlutinBackend {
             glutin_context: glutin_context.clone(),
-            surface_texture,
+            surface_texture: surface_texture.clone(),
             texture_id,
+            dimensions: dimensions.clone(),
         };
+        let proc_address_glutin = glutin_context.clone();
+        let get_proc_address: Rc<dyn Fn(&str) -> *const c_void> = Rc::new(move |symbol: &str| {
+            proc_address_glutin.borrow().get_proc_address(symbol) as *const _
+        });
         let context = unsafe { context::Context::new(glutin_backend, checked, debug) }?;
         Ok(SurfaceBacked {
             context,
             glutin: glutin_context,
+            surface_texture,
+            texture_id,
+            dimensions,
+            get_proc_address,
+            _shared_with: None,
         })
     }
 
-    /// Borrow the inner glutin context
+    /// The same as `new`, but builds a context that shares GL objects (textures, buffers,
+    /// programs, ...) with `shared_with`.
+    ///
+    /// `context` must already have been built against the same config with GL object sharing
+    /// enabled against `shared_with`'s context, e.g. via
+    /// `glutin::ContextBuilder::new().with_shared_lists(&*shared_with.gl_context())`. This lets
+    /// several `SurfaceBacked` renderers upload a texture once on `shared_with` and sample it
+    /// from each other.
+    pub fn new_shared<T: ContextCurrentState>(
+        context: glutin::Context<T>,
+        shared_with: &SurfaceBacked,
+        surface_texture: SurfaceTexture,
+        texture_id: u32,
+        dimensions: (u32, u32),
+    ) -> Result<Self, IncompatibleOpenGl> {
+        Self::with_debug_shared(
+            context,
+            shared_with,
+            Default::default(),
+            surface_texture,
+            texture_id,
+            dimensions,
+        )
+    }
+
+    /// The same as `new_shared`, but allows for specifying debug callback behaviour.
+    pub fn with_debug_shared<T: ContextCurrentState>(
+        context: glutin::Context<T>,
+        shared_with: &SurfaceBacked,
+        debug: debug::DebugCallbackBehavior,
+        surface_texture: SurfaceTexture,
+        texture_id: u32,
+        dimensions: (u32, u32),
+    ) -> Result<Self, IncompatibleOpenGl> {
+        let mut surface_backed = Self::new_inner(
+            context,
+            debug,
+            true,
+            surface_texture,
+            texture_id,
+            dimensions,
+        )?;
+        surface_backed._shared_with = Some(shared_with.context.clone());
+        Ok(surface_backed)
+    }
+
+    /// Borrow the inner glutin context.
     pub fn gl_context(&self) -> Ref<'_, impl Deref<Target = glutin::Context<Pc>>> {
         self.glutin.borrow()
     }
 
+    /// Update the framebuffer dimensions reported by this backend and reconfigure the
+    /// underlying `SurfaceTexture` to match.
+    ///
+    /// Call this whenever the Android `Surface` backing this renderer changes size, for
+    /// example from `SurfaceHolder.Callback::surfaceChanged`. The next call to `draw` will
+    /// pick up the new size.
+    pub fn resize(&self, width: u32, height: u32) {
+        self.dimensions.set((width, height));
+        let surface_texture = self.surface_texture.borrow();
+        surface_texture.set_default_buffer_size(width, height);
+        surface_texture.attach_to_gl_context(self.texture_id);
+    }
+
+    /// Latch the most recently available buffer from the `SurfaceTexture` onto its external
+    /// texture, making it sampleable as the `ExternalTexture` returned by `external_texture`.
+    ///
+    /// This must be called with this context current, typically once per `draw`, and mirrors
+    /// `SurfaceTexture.updateTexImage()` on the Android side.
+    pub fn update_tex_image(&self) {
+        self.surface_texture.borrow().update_tex_image();
+    }
+
+    /// The `GL_TEXTURE_EXTERNAL_OES` texture last latched by `update_tex_image`.
+    pub fn external_texture(&self) -> ExternalTexture {
+        ExternalTexture::from_texture_id(self.texture_id)
+    }
+
+    /// The 4x4 transform matrix of the texture last latched by `update_tex_image`, to be
+    /// applied to texture coordinates before sampling, mirroring
+    /// `SurfaceTexture.getTransformMatrix()`.
+    pub fn transform_matrix(&self) -> [[f32; 4]; 4] {
+        let m = self.surface_texture.borrow().get_transform_matrix();
+        [
+            [m[0], m[1], m[2], m[3]],
+            [m[4], m[5], m[6], m[7]],
+            [m[8], m[9], m[10], m[11]],
+            [m[12], m[13], m[14], m[15]],
+        ]
+    }
+
     /// Start drawing on the backbuffer.
     ///
     /// This function returns a `Frame`, which can be used to draw on it. When the `Frame` is
@@ -160,6 +477,393 @@ impl SurfaceBacked {
     /// context will be resized accordingly before returning the `Frame`.
     #[inline]
     pub fn draw(&self) -> Frame {
-        Frame::new(self.context.clone(), self.get_framebuffer_dimensions())
+        Frame::new(self.context.clone(), self.dimensions.get())
+    }
+
+    /// Call `eglBindWaylandDisplayWL` so the EGL implementation can import buffers from
+    /// `wl_display`, letting `texture_from_wl_buffer` later turn a client's `wl_buffer` into a
+    /// sampleable glium texture. Returns whether the bind succeeded; `false` if the
+    /// `EGL_WL_bind_wayland_display` extension isn't available on this context.
+    ///
+    /// # Safety
+    ///
+    /// `egl_display` and `wl_display` must be the native `EGLDisplay` backing this context and a
+    /// valid `struct wl_display *`, respectively, and must outlive any textures later created
+    /// from buffers of that display.
+    pub unsafe fn bind_wl_display(
+        &self,
+        egl_display: *mut c_void,
+        wl_display: *mut c_void,
+    ) -> bool {
+        let bind_wayland_display_wl: Option<egl::BindWaylandDisplayWl> =
+            load_proc(&*self.get_proc_address, "eglBindWaylandDisplayWL");
+        match bind_wayland_display_wl {
+            Some(bind_wayland_display_wl) => {
+                bind_wayland_display_wl(egl_display, wl_display) != egl::FALSE
+            }
+            None => false,
+        }
+    }
+
+    /// The `get_proc_address` loader backing this context, for resolving extension entry points
+    /// not otherwise exposed, e.g. to pass to `texture_from_wl_buffer`.
+    pub fn proc_loader(&self) -> &(dyn Fn(&str) -> *const c_void) {
+        &*self.get_proc_address
+    }
+}
+
+impl<T: glutin::surface::SurfaceTypeTrait> RwhSurfaceBacked<T> {
+    /// Create a new glium context on top of the `raw-window-handle`-based glutin
+    /// `Display`/`Config`/`Context`/`Surface` split.
+    ///
+    /// `context` is made current on `surface` immediately; presentation is then driven by
+    /// `surface`'s own `swap_buffers`, and the reported framebuffer size always reflects the
+    /// surface's current size.
+    pub fn from_surface(
+        display: glutin::display::Display,
+        context: glutin::context::NotCurrentContext,
+        surface: glutin::surface::Surface<T>,
+    ) -> Result<Self, IncompatibleOpenGl> {
+        Self::from_surface_with_debug(display, context, surface, Default::default())
+    }
+
+    /// The same as `from_surface`, but allows for specifying debug callback behaviour.
+    pub fn from_surface_with_debug(
+        display: glutin::display::Display,
+        context: glutin::context::NotCurrentContext,
+        surface: glutin::surface::Surface<T>,
+        debug: debug::DebugCallbackBehavior,
+    ) -> Result<Self, IncompatibleOpenGl> {
+        let surface = Rc::new(surface);
+        let gl_context = Rc::new(context.make_current(&surface).map_err(|err| {
+            IncompatibleOpenGl(format!(
+                "failed to make the glutin context current: {}",
+                err
+            ))
+        })?);
+        let proc_address_display = display.clone();
+        let rwh_backend = RwhBackend {
+            display,
+            context: gl_context.clone(),
+            surface: surface.clone(),
+        };
+        let context = unsafe { context::Context::new(rwh_backend, true, debug) }?;
+        let get_proc_address: Rc<dyn Fn(&str) -> *const c_void> = Rc::new(move |symbol: &str| {
+            let symbol = CString::new(symbol).unwrap();
+            proc_address_display.get_proc_address(symbol.as_c_str())
+        });
+        Ok(RwhSurfaceBacked {
+            context,
+            gl_context,
+            surface,
+            get_proc_address,
+            _shared_with: None,
+        })
+    }
+
+    /// Borrow the raw glutin context, for building a `glutin::context::ContextAttributesBuilder`
+    /// that shares GL objects with it via `from_surface_shared`.
+    pub fn context_handle(&self) -> &glutin::context::PossiblyCurrentContext {
+        &self.gl_context
+    }
+
+    /// The same as `from_surface`, but builds a context that shares GL objects (textures,
+    /// buffers, programs, ...) with `shared_with`.
+    ///
+    /// `context` must already have been built with GL object sharing enabled against
+    /// `shared_with`'s context, e.g. via a `glutin::context::ContextAttributesBuilder` whose
+    /// sharing context is `shared_with`'s raw context. `shared_with` must be another
+    /// `RwhSurfaceBacked`: sharing across the legacy and `raw-window-handle` backends isn't
+    /// supported, since they're built from independent glutin context builders.
+    pub fn from_surface_shared<U: glutin::surface::SurfaceTypeTrait>(
+        display: glutin::display::Display,
+        context: glutin::context::NotCurrentContext,
+        surface: glutin::surface::Surface<T>,
+        shared_with: &RwhSurfaceBacked<U>,
+    ) -> Result<Self, IncompatibleOpenGl> {
+        Self::from_surface_with_debug_shared(
+            display,
+            context,
+            surface,
+            shared_with,
+            Default::default(),
+        )
+    }
+
+    /// The same as `from_surface_shared`, but allows for specifying debug callback behaviour.
+    pub fn from_surface_with_debug_shared<U: glutin::surface::SurfaceTypeTrait>(
+        display: glutin::display::Display,
+        context: glutin::context::NotCurrentContext,
+        surface: glutin::surface::Surface<T>,
+        shared_with: &RwhSurfaceBacked<U>,
+        debug: debug::DebugCallbackBehavior,
+    ) -> Result<Self, IncompatibleOpenGl> {
+        let mut surface_backed = Self::from_surface_with_debug(display, context, surface, debug)?;
+        surface_backed._shared_with = Some(shared_with.context.clone());
+        Ok(surface_backed)
+    }
+
+    /// Start drawing on the backbuffer.
+    ///
+    /// This function returns a `Frame`, which can be used to draw on it. When the `Frame` is
+    /// destroyed, the buffers are swapped via the underlying `Surface::swap_buffers`.
+    #[inline]
+    pub fn draw(&self) -> Frame {
+        let dimensions = (
+            self.surface.width().unwrap_or(0),
+            self.surface.height().unwrap_or(0),
+        );
+        Frame::new(self.context.clone(), dimensions)
+    }
+
+    /// Call `eglBindWaylandDisplayWL` so the EGL implementation can import buffers from
+    /// `wl_display`, letting `texture_from_wl_buffer` later turn a client's `wl_buffer` into a
+    /// sampleable glium texture. Returns whether the bind succeeded; `false` if the
+    /// `EGL_WL_bind_wayland_display` extension isn't available on this context.
+    ///
+    /// # Safety
+    ///
+    /// `egl_display` and `wl_display` must be the native `EGLDisplay` backing this context and a
+    /// valid `struct wl_display *`, respectively, and must outlive any textures later created
+    /// from buffers of that display.
+    pub unsafe fn bind_wl_display(
+        &self,
+        egl_display: *mut c_void,
+        wl_display: *mut c_void,
+    ) -> bool {
+        let bind_wayland_display_wl: Option<egl::BindWaylandDisplayWl> =
+            load_proc(&*self.get_proc_address, "eglBindWaylandDisplayWL");
+        match bind_wayland_display_wl {
+            Some(bind_wayland_display_wl) => {
+                bind_wayland_display_wl(egl_display, wl_display) != egl::FALSE
+            }
+            None => false,
+        }
+    }
+
+    /// The `get_proc_address` loader backing this context, for resolving extension entry points
+    /// not otherwise exposed, e.g. to pass to `texture_from_wl_buffer`.
+    pub fn proc_loader(&self) -> &(dyn Fn(&str) -> *const c_void) {
+        &*self.get_proc_address
+    }
+}
+
+/// The reason `texture_from_wl_buffer` failed to import a Wayland client buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WlBufferImportError {
+    /// The EGL implementation doesn't expose the named entry point, e.g. because
+    /// `EGL_KHR_image_base` or `GL_OES_EGL_image` isn't supported.
+    MissingEntryPoint(&'static str),
+    /// `eglCreateImageKHR` returned `EGL_NO_IMAGE`; `client_buffer` likely isn't a valid
+    /// `wl_buffer` for `egl_display`.
+    ImageCreationFailed,
+}
+
+impl std::fmt::Display for WlBufferImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WlBufferImportError::MissingEntryPoint(name) => {
+                write!(f, "missing EGL entry point: {}", name)
+            }
+            WlBufferImportError::ImageCreationFailed => write!(f, "eglCreateImageKHR failed"),
+        }
+    }
+}
+
+impl std::error::Error for WlBufferImportError {}
+
+/// Import a Wayland client buffer as a sampleable `GL_TEXTURE_EXTERNAL_OES` glium texture.
+///
+/// Wraps `client_buffer` (obtained from the compositor side, e.g. via
+/// `wl_resource_get_user_data`/`wl_shm_buffer_get` equivalents for an EGL buffer) in an
+/// `EGLImageKHR` with `eglCreateImageKHR`, allocates a GL texture, and attaches the image to it
+/// with `glEGLImageTargetTexture2DOES` — the same target `SurfaceBacked` already uses for
+/// Android `SurfaceTexture`s. The `EGLImageKHR` is destroyed again once it's attached to the
+/// texture, since `glEGLImageTargetTexture2DOES` only needs it for the duration of that call.
+///
+/// `get_proc_address` must resolve entry points on the same context as `egl_display` and
+/// `client_buffer`'s `bind_wl_display` call — pass `SurfaceBacked::proc_loader` or
+/// `RwhSurfaceBacked::proc_loader` for the context that did the bind, which must also be
+/// current.
+///
+/// # Safety
+///
+/// `egl_display` and `client_buffer` must be valid native EGL/Wayland handles for the duration
+/// of this call.
+pub unsafe fn texture_from_wl_buffer(
+    get_proc_address: &dyn Fn(&str) -> *const c_void,
+    egl_display: *mut c_void,
+    client_buffer: *mut c_void,
+) -> Result<ExternalTexture, WlBufferImportError> {
+    let create_image_khr: egl::CreateImageKhr = load_proc(get_proc_address, "eglCreateImageKHR")
+        .ok_or(WlBufferImportError::MissingEntryPoint("eglCreateImageKHR"))?;
+    let destroy_image_khr: egl::DestroyImageKhr = load_proc(get_proc_address, "eglDestroyImageKHR")
+        .ok_or(WlBufferImportError::MissingEntryPoint("eglDestroyImageKHR"))?;
+    let gen_textures: unsafe extern "C" fn(i32, *mut u32) =
+        load_proc(get_proc_address, "glGenTextures")
+            .ok_or(WlBufferImportError::MissingEntryPoint("glGenTextures"))?;
+    let bind_texture: unsafe extern "C" fn(u32, u32) = load_proc(get_proc_address, "glBindTexture")
+        .ok_or(WlBufferImportError::MissingEntryPoint("glBindTexture"))?;
+    let image_target_texture_2d_oes: unsafe extern "C" fn(u32, *mut c_void) =
+        load_proc(get_proc_address, "glEGLImageTargetTexture2DOES").ok_or(
+            WlBufferImportError::MissingEntryPoint("glEGLImageTargetTexture2DOES"),
+        )?;
+
+    let image = create_image_khr(
+        egl_display,
+        egl::NO_CONTEXT,
+        egl::WAYLAND_BUFFER_WL,
+        client_buffer,
+        std::ptr::null(),
+    );
+    if image.is_null() {
+        return Err(WlBufferImportError::ImageCreationFailed);
+    }
+
+    let mut texture_id = 0u32;
+    gen_textures(1, &mut texture_id);
+    bind_texture(TEXTURE_EXTERNAL_OES, texture_id);
+    image_target_texture_2d_oes(TEXTURE_EXTERNAL_OES, image);
+    destroy_image_khr(egl_display, image);
+
+    Ok(ExternalTexture::from_texture_id(texture_id))
+}
+
+/// A facade presenting a host-supplied framebuffer object as glium's backbuffer, parallel to
+/// `SurfaceBacked`.
+///
+/// Use this to embed glium inside a host that already owns the GL context and a render target
+/// FBO — e.g. a GTK `GLArea`, which binds its own framebuffer before invoking the `render`
+/// callback — instead of assuming glium owns the default framebuffer (FBO 0).
+pub struct FramebufferBacked {
+    context: Rc<context::Context>,
+    framebuffer_id: u32,
+    dimensions: Rc<Cell<(u32, u32)>>,
+}
+
+/// An implementation of the `Backend` trait for a caller-supplied framebuffer object.
+struct FramebufferBackend {
+    framebuffer_id: u32,
+    dimensions: Rc<Cell<(u32, u32)>>,
+    get_proc_address: Rc<dyn Fn(&str) -> *const c_void>,
+}
+
+impl FramebufferBackend {
+    unsafe fn bind(&self) {
+        if let Some(bind_framebuffer) = load_proc::<unsafe extern "C" fn(u32, u32)>(
+            &*self.get_proc_address,
+            "glBindFramebuffer",
+        ) {
+            bind_framebuffer(GL_FRAMEBUFFER, self.framebuffer_id);
+        }
+    }
+}
+
+unsafe impl Backend for FramebufferBackend {
+    #[inline]
+    fn swap_buffers(&self) -> Result<(), SwapBuffersError> {
+        // There is no real backbuffer to swap: the host owns the framebuffer and presents it
+        // itself, so all we do is make sure it is still the one bound.
+        unsafe { self.bind() };
+        Ok(())
+    }
+
+    #[inline]
+    unsafe fn get_proc_address(&self, symbol: &str) -> *const c_void {
+        (self.get_proc_address)(symbol)
+    }
+
+    #[inline]
+    fn get_framebuffer_dimensions(&self) -> (u32, u32) {
+        self.dimensions.get()
+    }
+
+    #[inline]
+    fn is_current(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    unsafe fn make_current(&self) {
+        self.bind();
+    }
+}
+
+impl Deref for FramebufferBacked {
+    type Target = context::Context;
+    fn deref(&self) -> &context::Context {
+        &self.context
+    }
+}
+
+impl backend::Facade for FramebufferBacked {
+    #[inline]
+    fn get_context(&self) -> &Rc<context::Context> {
+        &self.context
+    }
+}
+
+impl FramebufferBacked {
+    /// Create a new glium `FramebufferBacked` context targeting `framebuffer_id`, a framebuffer
+    /// object the host has already created (and bound) on the current GL context.
+    ///
+    /// `get_proc_address` must resolve GL function pointers on the host's context, exactly like
+    /// the loader callback a windowing toolkit's GL area already hands out (e.g. GTK's
+    /// `GLArea` via `epoxy_get_proc_address`).
+    pub fn new(
+        framebuffer_id: u32,
+        dimensions: (u32, u32),
+        get_proc_address: impl Fn(&str) -> *const c_void + 'static,
+    ) -> Result<Self, IncompatibleOpenGl> {
+        Self::with_debug(
+            framebuffer_id,
+            dimensions,
+            get_proc_address,
+            Default::default(),
+        )
+    }
+
+    /// The same as `new`, but allows for specifying debug callback behaviour.
+    pub fn with_debug(
+        framebuffer_id: u32,
+        dimensions: (u32, u32),
+        get_proc_address: impl Fn(&str) -> *const c_void + 'static,
+        debug: debug::DebugCallbackBehavior,
+    ) -> Result<Self, IncompatibleOpenGl> {
+        let get_proc_address: Rc<dyn Fn(&str) -> *const c_void> = Rc::new(get_proc_address);
+        let dimensions = Rc::new(Cell::new(dimensions));
+        let backend = FramebufferBackend {
+            framebuffer_id,
+            dimensions: dimensions.clone(),
+            get_proc_address,
+        };
+        let context = unsafe { context::Context::new(backend, true, debug) }?;
+        Ok(FramebufferBacked {
+            context,
+            framebuffer_id,
+            dimensions,
+        })
+    }
+
+    /// The host-supplied framebuffer object this context renders into.
+    #[inline]
+    pub fn framebuffer_id(&self) -> u32 {
+        self.framebuffer_id
+    }
+
+    /// Update the dimensions reported for this framebuffer, e.g. from the host's own resize
+    /// callback.
+    pub fn resize(&self, width: u32, height: u32) {
+        self.dimensions.set((width, height));
+    }
+
+    /// Start drawing on the host-supplied framebuffer.
+    ///
+    /// This function returns a `Frame` targeting `framebuffer_id` rather than the default
+    /// framebuffer. When the `Frame` is destroyed, `swap_buffers` is a no-op beyond re-binding
+    /// `framebuffer_id`, since this backend never owns a real backbuffer to swap.
+    #[inline]
+    pub fn draw(&self) -> Frame {
+        Frame::new(self.context.clone(), self.dimensions.get())
     }
 }